@@ -1,5 +1,5 @@
 use crate::result;
-use crate::{cursor::Cursor, cursor::CursorState, OpResult};
+use crate::{cursor::Cursor, cursor::CursorSeek, cursor::CursorState, OpResult};
 use std::ops::{Deref, DerefMut};
 
 /// Vector container with inner cursor variable
@@ -74,6 +74,7 @@ impl<T> CursorVec<T> {
     pub fn with_container(mut self, vector: Vec<T>) -> Self {
         self.vector = vector;
         self.cursor.set_capacity(self.vector.len());
+        self.cursor.reset_lap();
         self
     }
 
@@ -92,6 +93,7 @@ impl<T> CursorVec<T> {
     pub fn set_container(&mut self, container: Vec<T>) {
         self.vector = container;
         self.update_cursor();
+        self.cursor.reset_lap();
     }
 
     /// Modify inner container with given closure
@@ -274,6 +276,131 @@ impl<T> CursorVec<T> {
         }
     }
 
+    /// Peek at the value `amount` steps ahead without moving the cursor
+    ///
+    /// Uses the same bounds/rotation logic as [move_next_nth_and_get](CursorVec::move_next_nth_and_get)
+    /// so the returned [CursorState] explains exactly why a lookahead isn't available.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::{CursorVec, CursorState};
+    ///
+    /// let vec = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// assert_eq!(Some(&3), vec.peek_next_nth(2).value());
+    /// assert_eq!(CursorState::MaxOut, vec.peek_next_nth(3));
+    /// assert_eq!(Some(0), vec.get_cursor()); // cursor itself never moved
+    /// ```
+    pub fn peek_next_nth(&self, amount: usize) -> CursorState<T> {
+        if self.is_empty_container() {
+            return CursorState::EmptyContainer;
+        }
+        let mut probe = self.cursor.clone();
+        for _ in 0..amount {
+            if !result::is_true(probe.increase()) {
+                return CursorState::MaxOut;
+            }
+        }
+        match self.vector.get(probe.get_value()) {
+            Some(v) => CursorState::Valid(v),
+            None => CursorState::OutOfRange,
+        }
+    }
+
+    /// Peek at the immediately next value without moving the cursor
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::CursorVec;
+    ///
+    /// let vec = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// assert_eq!(Some(&2), vec.peek_next().value());
+    /// assert_eq!(Some(0), vec.get_cursor());
+    /// ```
+    pub fn peek_next(&self) -> CursorState<T> {
+        self.peek_next_nth(1)
+    }
+
+    /// Peek at the value `amount` steps behind without moving the cursor
+    ///
+    /// Uses the same bounds/rotation logic as [move_prev_nth_and_get](CursorVec::move_prev_nth_and_get)
+    /// so the returned [CursorState] explains exactly why a lookbehind isn't available.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::{CursorVec, CursorState, CursorSeek};
+    ///
+    /// let mut vec = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// vec.seek(CursorSeek::End(0));
+    /// assert_eq!(Some(&1), vec.peek_prev_nth(2).value());
+    /// assert_eq!(CursorState::MinOut, vec.peek_prev_nth(3));
+    /// assert_eq!(Some(2), vec.get_cursor()); // cursor itself never moved
+    /// ```
+    pub fn peek_prev_nth(&self, amount: usize) -> CursorState<T> {
+        if self.is_empty_container() {
+            return CursorState::EmptyContainer;
+        }
+        let mut probe = self.cursor.clone();
+        for _ in 0..amount {
+            if !result::is_true(probe.decrease()) {
+                return CursorState::MinOut;
+            }
+        }
+        match self.vector.get(probe.get_value()) {
+            Some(v) => CursorState::Valid(v),
+            None => CursorState::OutOfRange,
+        }
+    }
+
+    /// Peek at the immediately previous value without moving the cursor
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::{CursorVec, CursorSeek};
+    ///
+    /// let mut vec = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// vec.seek(CursorSeek::End(0));
+    /// assert_eq!(Some(&2), vec.peek_prev().value());
+    /// assert_eq!(Some(2), vec.get_cursor());
+    /// ```
+    pub fn peek_prev(&self) -> CursorState<T> {
+        self.peek_prev_nth(1)
+    }
+
+    /// Seek cursor to an absolute, end-relative or current-relative position and get its value
+    ///
+    /// Borrows the `Seek`/`SeekFrom` model from [std::io::Cursor]: `CursorSeek::Start(n)` sets
+    /// the cursor to `n`, `CursorSeek::End(offset)` counts back from the last index (`End(0)` is
+    /// the last element, `End(-2)` two before it), and `CursorSeek::Current(offset)` nudges the
+    /// cursor by a signed offset from where it is now.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::{CursorVec, CursorSeek, CursorState};
+    ///
+    /// let mut vec = CursorVec::new().with_container(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(Some(&5), vec.seek(CursorSeek::End(0)).value());
+    /// assert_eq!(Some(&3), vec.seek(CursorSeek::End(-2)).value());
+    /// assert_eq!(Some(&4), vec.seek(CursorSeek::Current(1)).value());
+    /// assert_eq!(Some(&1), vec.seek(CursorSeek::Start(0)).value());
+    /// ```
+    pub fn seek(&mut self, pos: CursorSeek) -> CursorState<T> {
+        if self.is_empty_container() {
+            return CursorState::EmptyContainer;
+        }
+        if !result::is_true(self.cursor.seek(pos)) {
+            return CursorState::OutOfRange;
+        }
+        match self.get_cursor_value() {
+            Some(v) => CursorState::Valid(v),
+            None => CursorState::OutOfRange,
+        }
+    }
+
     /// Move cursor to next
     pub fn move_next(&mut self) -> OpResult {
         if self.is_empty_container() {
@@ -309,6 +436,106 @@ impl<T> CursorVec<T> {
     }
     // </Manual> Methods
 
+    /// Get the number of completed rotations for a rotatable cursor
+    ///
+    /// Increments on every forward wrap (last index -> 0) and decrements on every backward wrap
+    /// (0 -> last index) caused by [move_next](CursorVec::move_next)-family navigation, so a
+    /// rotating carousel/playlist can detect a full cycle without tracking indices itself.
+    /// [seek](CursorVec::seek) does not affect this counter, since it jumps directly to a
+    /// position rather than stepping through it. Resets to zero on
+    /// [set_cursor](CursorVec::set_cursor), [set_container](CursorVec::set_container) and
+    /// [with_container](CursorVec::with_container).
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::CursorVec;
+    ///
+    /// let mut vec = CursorVec::new().rotatable(true).with_container(vec![1, 2, 3]);
+    /// vec.move_next_nth_and_get(5); // wraps past the end once
+    /// assert_eq!(1, vec.get_lap_count());
+    /// vec.set_cursor(0);
+    /// assert_eq!(0, vec.get_lap_count());
+    /// ```
+    pub fn get_lap_count(&self) -> isize {
+        self.cursor.get_lap()
+    }
+
+    /// Read the lap counter and reset it to zero
+    ///
+    /// Useful for "did we complete a cycle since I last checked" polling without keeping track
+    /// of the previous [get_lap_count](CursorVec::get_lap_count) value.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::CursorVec;
+    ///
+    /// let mut vec = CursorVec::new().rotatable(true).with_container(vec![1, 2, 3]);
+    /// vec.move_next_nth_and_get(5);
+    /// assert_eq!(1, vec.take_lap_delta());
+    /// assert_eq!(0, vec.get_lap_count()); // consumed
+    /// ```
+    pub fn take_lap_delta(&mut self) -> isize {
+        let delta = self.cursor.get_lap();
+        self.cursor.reset_lap();
+        delta
+    }
+
+    /// Create a cursor-advancing iterator starting at the current cursor position
+    ///
+    /// Yields values forward, moving the cursor one step on every call to `next`. A
+    /// non-rotatable cursor stops once the last element has been yielded; a rotatable cursor
+    /// yields exactly [len](CursorVec::len) items so it can't loop forever. Either way the
+    /// cursor is left parked on the last element the iterator produced.
+    ///
+    /// This is not a [std::iter::Iterator]: each `next()` call borrows from `self` independently,
+    /// which a single associated `Item` type can't express while the cursor is also being
+    /// mutated in between calls. Drive it with a `while let` loop instead of `for`/`.collect()`.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use cursorvec::CursorVec;
+    ///
+    /// let mut vec = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// let mut iter = vec.cursor_iter();
+    /// let mut collected = vec![];
+    /// while let Some(value) = iter.next() {
+    ///     collected.push(*value);
+    /// }
+    /// assert_eq!(vec![1, 2, 3], collected);
+    /// assert_eq!(Some(2), vec.get_cursor());
+    /// ```
+    pub fn cursor_iter(&mut self) -> CursorIter<T> {
+        let remaining = if self.is_empty_container() {
+            None
+        } else if self.cursor.is_rotatable() {
+            Some(self.vector.len())
+        } else {
+            self.get_cursor().map(|idx| self.vector.len().saturating_sub(idx))
+        };
+        CursorIter {
+            source: self,
+            remaining,
+        }
+    }
+
+    /// Create a cursor-advancing iterator starting at a given index
+    ///
+    /// Equivalent to calling [set_cursor](CursorVec::set_cursor) followed by
+    /// [cursor_iter](CursorVec::cursor_iter). If `start` is out of range, `set_cursor` fails and
+    /// the returned iterator is empty rather than silently resuming from the old cursor position.
+    pub fn cursor_iter_from(&mut self, start: usize) -> CursorIter<T> {
+        if !result::is_true(self.set_cursor(start)) {
+            return CursorIter {
+                source: self,
+                remaining: None,
+            };
+        }
+        self.cursor_iter()
+    }
+
     // <DRY> Codes
 
     fn get_cursor_value(&self) -> Option<&T> {
@@ -320,3 +547,37 @@ impl<T> CursorVec<T> {
     }
     // </DRY>
 }
+
+/// Cursor-advancing streaming iterator produced by [CursorVec::cursor_iter]/[CursorVec::cursor_iter_from]
+///
+/// Deliberately not a [std::iter::Iterator]: an `Iterator` impl would have to tie every yielded
+/// `&T` to the iterator's own lifetime rather than to each individual `next()` call, which keeps
+/// the source `CursorVec` borrowed for as long as any yielded reference (or a `Vec` collected
+/// from them) is alive - so, for example, `source.get_cursor()` couldn't be called again until
+/// every collected reference was dropped. Use `next()` directly (`while let Some(v) = iter.next()`).
+pub struct CursorIter<'source, T> {
+    source: &'source mut CursorVec<T>,
+    remaining: Option<usize>,
+}
+
+impl<'source, T> CursorIter<'source, T> {
+    /// Get the next value and advance the cursor, or `None` once exhausted
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&T> {
+        let remaining = self.remaining?;
+        if remaining == 0 {
+            self.remaining = None;
+            return None;
+        }
+
+        let idx = self.source.get_cursor()?;
+        self.remaining = Some(remaining - 1);
+        if remaining > 1 {
+            #[allow(unused_must_use)]
+            {
+                self.source.move_next();
+            }
+        }
+        self.source.get(idx)
+    }
+}
@@ -2,11 +2,12 @@ use crate::result;
 use crate::OpResult;
 
 /// Cursor that points value
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub(crate) struct Cursor {
     capacity: usize,
     rotation: bool,
     index: usize,
+    lap: isize,
 }
 
 impl Cursor {
@@ -15,6 +16,7 @@ impl Cursor {
             capacity,
             rotation: false,
             index: 0,
+            lap: 0,
         }
     }
 
@@ -26,15 +28,30 @@ impl Cursor {
         self.index
     }
 
+    pub fn is_rotatable(&self) -> bool {
+        self.rotation
+    }
+
     pub fn set_value(&mut self, value: usize) -> OpResult {
         if value > self.capacity {
             result::error("Cursor out of range")
         } else {
             self.index = value;
+            self.lap = 0;
             result::ok()
         }
     }
 
+    /// Number of completed rotations; positive for forward wraps, negative for backward wraps
+    pub fn get_lap(&self) -> isize {
+        self.lap
+    }
+
+    /// Reset the lap counter to zero without touching the cursor's position
+    pub fn reset_lap(&mut self) {
+        self.lap = 0;
+    }
+
     pub fn set_capacity(&mut self, capacity: usize) {
         self.capacity = capacity;
         if self.index >= self.capacity {
@@ -46,9 +63,15 @@ impl Cursor {
         if self.capacity == 0 {
             return result::error("Empty container");
         };
-        if self.index == self.capacity - 1 {
+        // `>=`, not `==`: the index can sit one past the last valid slot (e.g. `CursorVec<u8>`'s
+        // `Read`/`Write` impls rest it at `len` to mark the stream's EOF/append position) without
+        // ever having gone through this method, so treat "at or past the last index" as the same
+        // boundary rather than only the exact last index - otherwise an already-overshot index
+        // just keeps incrementing forever without ever reporting `MaxOut`.
+        if self.index >= self.capacity - 1 {
             if self.rotation {
                 self.index = 0;
+                self.lap += 1;
                 result::ok()
             } else {
                 result::error("Cursor out of range")
@@ -66,6 +89,7 @@ impl Cursor {
         if self.index == 0 {
             if self.rotation {
                 self.index = self.capacity - 1;
+                self.lap -= 1;
                 result::ok()
             } else {
                 result::error("Cursor out of range")
@@ -75,6 +99,54 @@ impl Cursor {
             result::ok()
         }
     }
+
+    /// Move cursor to a position computed from a [CursorSeek] target
+    ///
+    /// Wraps modulo capacity when rotation is enabled, otherwise errors on out of range targets
+    /// exactly like [increase](Cursor::increase)/[decrease](Cursor::decrease). An offset large
+    /// enough to overflow `isize` arithmetic is itself treated as out of range rather than
+    /// panicking, since `CursorSeek` is a public enum and any offset is valid input.
+    ///
+    /// Unlike `increase`/`decrease`, this does not touch the lap counter: `seek` is an absolute
+    /// jump, not a sequential step, so "how many wraps occurred" isn't well defined for it.
+    pub fn seek(&mut self, pos: CursorSeek) -> OpResult {
+        if self.capacity == 0 {
+            return result::error("Empty container");
+        };
+        let target = match pos {
+            CursorSeek::Start(n) => isize::try_from(n).ok(),
+            CursorSeek::End(offset) => (self.capacity as isize - 1).checked_add(offset),
+            CursorSeek::Current(offset) => (self.index as isize).checked_add(offset),
+        };
+        let target = match target {
+            Some(target) => target,
+            None => return result::error("Cursor out of range"),
+        };
+
+        if self.rotation {
+            let capacity = self.capacity as isize;
+            self.index = (((target % capacity) + capacity) % capacity) as usize;
+            result::ok()
+        } else if target < 0 || target as usize >= self.capacity {
+            result::error("Cursor out of range")
+        } else {
+            self.index = target as usize;
+            result::ok()
+        }
+    }
+}
+
+/// Target position for [Cursor::seek](Cursor::seek) / [CursorVec::seek](crate::CursorVec::seek)
+///
+/// Mirrors the shape of [std::io::SeekFrom]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CursorSeek {
+    /// Seek to an absolute index from the start of the container
+    Start(usize),
+    /// Seek relative to the last index of the container, e.g. `End(0)` is the last element
+    End(isize),
+    /// Seek relative to the current cursor position
+    Current(isize),
 }
 
 /// State of a cursor
@@ -0,0 +1,136 @@
+//! `std::io::Read`/`Write`/`Seek` impls for `CursorVec<u8>`, gated behind the `std-io` feature
+//!
+//! The cursor index doubles as the stream position, so `CursorVec<u8>` can drop into any
+//! `Read + Write + Seek` slot (serialization, test doubles for files) while still exposing the
+//! crate's cursor-navigation methods.
+
+use crate::result;
+use crate::CursorVec;
+use std::io::{Read, Result as IoResult, Seek, SeekFrom, Write};
+
+impl Read for CursorVec<u8> {
+    /// Copy bytes forward from the cursor position and advance the cursor by the amount read
+    ///
+    /// The cursor may come to rest at `len` (one past the last element) once reading reaches the
+    /// end - the legitimate EOF/append stream position, mirroring `std::io::Cursor`. A repeated
+    /// `read` there correctly yields `0` rather than re-reading, and
+    /// [get_current](CursorVec::get_current) correctly reports `OutOfRange` there since no
+    /// element exists at that position; [move_next](CursorVec::move_next)-family navigation
+    /// still reports `MaxOut` normally instead of running away past the end.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std-io")]
+    /// # {
+    /// use cursorvec::CursorVec;
+    /// use std::io::Read;
+    ///
+    /// let mut vec: CursorVec<u8> = CursorVec::new().with_container(vec![1, 2, 3, 4, 5]);
+    /// let mut buf = [0u8; 5];
+    /// assert_eq!(5, vec.read(&mut buf).unwrap());
+    /// assert_eq!([1, 2, 3, 4, 5], buf);
+    ///
+    /// // Reading past the end stays well-behaved: 0 bytes, no runaway cursor
+    /// assert_eq!(0, vec.read(&mut buf).unwrap());
+    /// assert_eq!(cursorvec::CursorState::MaxOut, vec.move_next_and_get());
+    /// # }
+    /// ```
+    #[allow(unused_must_use)]
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let pos = self.get_cursor().unwrap_or(0).min(self.len());
+        let available = &self[pos..];
+        let amount = available.len().min(buf.len());
+        buf[..amount].copy_from_slice(&available[..amount]);
+        self.set_cursor(pos + amount);
+        Ok(amount)
+    }
+}
+
+impl Write for CursorVec<u8> {
+    /// Overwrite bytes starting at the cursor position, growing the vector if necessary, and
+    /// advance the cursor by the amount written
+    ///
+    /// Like [read](CursorVec::read), the cursor may come to rest at `len` (the append position)
+    /// rather than the last valid index; see its docs for what that means for navigation.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std-io")]
+    /// # {
+    /// use cursorvec::CursorVec;
+    /// use std::io::Write;
+    ///
+    /// let mut vec: CursorVec<u8> = CursorVec::new().with_container(vec![0, 0, 0]);
+    /// assert_eq!(3, vec.write(&[1, 2, 3]).unwrap());
+    /// assert_eq!(&[1u8, 2, 3], &vec[..]);
+    /// # }
+    /// ```
+    #[allow(unused_must_use)]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let pos = self.get_cursor().unwrap_or(0).min(self.len());
+        if pos + buf.len() > self.len() {
+            self.resize(pos + buf.len(), 0);
+        }
+        self[pos..pos + buf.len()].copy_from_slice(buf);
+        self.update_cursor();
+        self.set_cursor(pos + buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+impl Seek for CursorVec<u8> {
+    /// Map `SeekFrom` onto the cursor, keeping it as the stream position
+    ///
+    /// `SeekFrom::End(n)` is computed as `len as i64 + n` directly rather than through
+    /// [CursorVec::seek]'s [crate::CursorSeek::End], which means "last index" (`len - 1`); the two
+    /// reference points differ by one, and `std::io::Cursor`'s seek-to-end-then-append idiom
+    /// needs the stream-position meaning (`len`, the append point) to behave correctly.
+    ///
+    /// `CursorVec` also has an inherent `seek` taking [CursorSeek]; from generic `Seek` bounds
+    /// this impl is picked up normally, but calling it directly on a concrete `CursorVec<u8>`
+    /// needs fully qualified syntax, e.g. `Seek::seek(&mut vec, SeekFrom::Start(0))`.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std-io")]
+    /// # {
+    /// use cursorvec::CursorVec;
+    /// use std::io::{Seek, SeekFrom, Write};
+    ///
+    /// let mut vec: CursorVec<u8> = CursorVec::new().with_container(vec![1, 2, 3]);
+    /// assert_eq!(3, Seek::seek(&mut vec, SeekFrom::End(0)).unwrap());
+    /// vec.write(&[9]).unwrap();
+    /// assert_eq!(&[1u8, 2, 3, 9], &vec[..]);
+    /// # }
+    /// ```
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        let current = self.get_cursor().unwrap_or(0) as i64;
+        let target = match pos {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::End(n) => self.len() as i64 + n,
+            SeekFrom::Current(n) => current + n,
+        };
+
+        if target < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            ));
+        }
+
+        if !result::is_true(self.set_cursor(target as usize)) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Cursor out of range",
+            ));
+        }
+        Ok(target as u64)
+    }
+}
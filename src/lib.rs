@@ -20,6 +20,10 @@
 //! If strict feature is enabled, previous operations that returned boolean value will return a
 //! result with error messages. User can redirect those results and handle errors more precisely.
 //!
+//! Enabling the ```std-io``` feature implements [Read](std::io::Read), [Write](std::io::Write)
+//! and [Seek](std::io::Seek) for ```CursorVec<u8>```, treating the cursor index as the stream
+//! position so it can drop into any API that expects a ```Read + Write + Seek``` buffer.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -84,9 +88,11 @@
 //! ```
 mod container;
 mod cursor;
+#[cfg(feature = "std-io")]
+mod io;
 mod result;
 mod test;
 
-pub use container::CursorVec;
-pub use cursor::CursorState;
+pub use container::{CursorIter, CursorVec};
+pub use cursor::{CursorSeek, CursorState};
 pub use result::OpResult;